@@ -1,12 +1,19 @@
 use std::collections::HashMap;
+use std::pin::Pin;
+use std::time::Duration;
 
+use futures::Stream;
 use quote::{InternalQuoteRequest, QuoteRequest, QuoteResponse};
-use reqwest::{Client, Response};
+use reqwest::Response;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use swap::{SwapInstructionsResponse, SwapInstructionsResponseInternal, SwapRequest, SwapResponse};
 use thiserror::Error;
 use price::{PriceRequest, PriceResponse};
+use price_quality::{PriceQuality, ValidatedPricesResponse};
+use price_v1::{PriceV1Request, PriceV1Response};
+use transport::{JupiterTransport, ReqwestTransport};
 
 pub mod quote;
 pub mod route_plan_with_metadata;
@@ -14,10 +21,77 @@ pub mod serde_helpers;
 pub mod swap;
 pub mod transaction_config;
 pub mod price;
+pub mod price_amount;
+pub mod price_quality;
+pub mod price_v1;
+pub mod transport;
+
+/// Default number of idle pooled connections kept open per host.
+const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 8;
+/// Default timeout applied to every request issued by the client.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[derive(Clone)]
-pub struct JupiterSwapApiClient {
+pub struct JupiterSwapApiClient<T: JupiterTransport = ReqwestTransport> {
     pub base_path: String,
+    transport: T,
+    endpoints: ApiEndpoints,
+}
+
+/// Configuration used to build a [`JupiterSwapApiClient`] with custom connection pooling,
+/// timeouts, and default headers (e.g. an API key).
+///
+/// Construct one with [`ClientConfig::default`] and adjust the fields you care about, then
+/// pass it to [`JupiterSwapApiClient::new_with_config`].
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Maximum number of idle connections kept alive per host.
+    pub pool_max_idle_per_host: usize,
+    /// Timeout applied to the whole request/response cycle.
+    pub timeout: Duration,
+    /// Headers sent on every request (e.g. `x-api-key`).
+    pub default_headers: reqwest::header::HeaderMap,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            pool_max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            default_headers: reqwest::header::HeaderMap::new(),
+        }
+    }
+}
+
+/// Overridable endpoint paths, for pointing the client at a self-hosted Jupiter instance, the
+/// lite vs pro hosts, or an alternate price API version.
+///
+/// Construct one with [`ApiEndpoints::default`] and adjust the paths you care about, then pass
+/// it to [`JupiterSwapApiClient::with_endpoints`].
+#[derive(Debug, Clone)]
+pub struct ApiEndpoints {
+    /// Path for [`JupiterSwapApiClient::quote`].
+    pub quote: String,
+    /// Path for [`JupiterSwapApiClient::swap`].
+    pub swap: String,
+    /// Path for [`JupiterSwapApiClient::swap_instructions`].
+    pub swap_instructions: String,
+    /// Path for [`JupiterSwapApiClient::get_prices_v1`].
+    pub price_v1: String,
+    /// Path for [`JupiterSwapApiClient::get_prices`].
+    pub price_v2: String,
+}
+
+impl Default for ApiEndpoints {
+    fn default() -> Self {
+        Self {
+            quote: "/quote".to_string(),
+            swap: "/swap".to_string(),
+            swap_instructions: "/swap-instructions".to_string(),
+            price_v1: "/price/v1".to_string(),
+            price_v2: "/price/v2".to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -40,7 +114,7 @@ async fn check_is_success(response: Response) -> Result<Response, ClientError> {
     Ok(response)
 }
 
-async fn check_status_code_and_deserialize<T: DeserializeOwned>(
+pub(crate) async fn check_status_code_and_deserialize<T: DeserializeOwned>(
     response: Response,
 ) -> Result<T, ClientError> {
     let response = check_is_success(response).await?;
@@ -50,22 +124,61 @@ async fn check_status_code_and_deserialize<T: DeserializeOwned>(
         .map_err(ClientError::DeserializationError)
 }
 
-impl JupiterSwapApiClient {
+/// A single query field paired with the `extra_args`/`quote_args` map, so both can be
+/// serialized onto one query string in one call.
+#[derive(Serialize)]
+struct FlattenedQuery<'a, Q: Serialize> {
+    #[serde(flatten)]
+    query: &'a Q,
+    #[serde(flatten)]
+    extra_args: &'a HashMap<String, String>,
+}
+
+impl JupiterSwapApiClient<ReqwestTransport> {
+    /// Create a client with a pooled [`reqwest::Client`] built using the default
+    /// [`ClientConfig`]. Use [`JupiterSwapApiClient::new_with_config`] to tune connection
+    /// pool size, timeouts, or default headers.
     pub fn new(base_path: String) -> Self {
-        Self { base_path }
+        Self::new_with_config(base_path, ClientConfig::default())
+    }
+
+    /// Create a client with a pooled [`reqwest::Client`] built from the given [`ClientConfig`].
+    ///
+    /// The returned `reqwest::Client` is reused across every request made through this
+    /// client, keeping TLS sessions and the connection pool warm instead of re-establishing
+    /// them on each call.
+    pub fn new_with_config(base_path: String, config: ClientConfig) -> Self {
+        Self::with_transport(base_path, ReqwestTransport::new(config))
+    }
+}
+
+impl<T: JupiterTransport> JupiterSwapApiClient<T> {
+    /// Create a client backed by a custom [`JupiterTransport`], e.g.
+    /// [`transport::MockTransport`] for offline tests.
+    pub fn with_transport(base_path: String, transport: T) -> Self {
+        Self {
+            base_path,
+            transport,
+            endpoints: ApiEndpoints::default(),
+        }
+    }
+
+    /// Override the endpoint paths used by this client, e.g. to target a self-hosted Jupiter
+    /// instance or a different price API version.
+    pub fn with_endpoints(mut self, endpoints: ApiEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
     }
 
     pub async fn quote(&self, quote_request: &QuoteRequest) -> Result<QuoteResponse, ClientError> {
-        let url = format!("{}/quote", self.base_path);
+        let url = format!("{}{}", self.base_path, self.endpoints.quote);
         let extra_args = quote_request.quote_args.clone();
         let internal_quote_request = InternalQuoteRequest::from(quote_request.clone());
-        let response = Client::new()
-            .get(url)
-            .query(&internal_quote_request)
-            .query(&extra_args)
-            .send()
-            .await?;
-        check_status_code_and_deserialize(response).await
+        let query = FlattenedQuery {
+            query: &internal_quote_request,
+            extra_args: &extra_args,
+        };
+        self.transport.get(url, &query).await
     }
 
     pub async fn swap(
@@ -73,42 +186,118 @@ impl JupiterSwapApiClient {
         swap_request: &SwapRequest,
         extra_args: Option<HashMap<String, String>>,
     ) -> Result<SwapResponse, ClientError> {
-        let response = Client::new()
-            .post(format!("{}/swap", self.base_path))
-            .query(&extra_args)
-            .json(swap_request)
-            .send()
-            .await?;
-        check_status_code_and_deserialize(response).await
+        let url = format!("{}{}", self.base_path, self.endpoints.swap);
+        match extra_args {
+            Some(extra_args) => self.transport.post_with_query(url, &extra_args, swap_request).await,
+            None => self.transport.post(url, swap_request).await,
+        }
     }
 
     pub async fn swap_instructions(
         &self,
         swap_request: &SwapRequest,
     ) -> Result<SwapInstructionsResponse, ClientError> {
-        let response = Client::new()
-            .post(format!("{}/swap-instructions", self.base_path))
-            .json(swap_request)
-            .send()
-            .await?;
-        check_status_code_and_deserialize::<SwapInstructionsResponseInternal>(response)
+        let url = format!("{}{}", self.base_path, self.endpoints.swap_instructions);
+        self.transport
+            .post::<SwapInstructionsResponseInternal>(url, swap_request)
             .await
             .map(Into::into)
     }
-    
+
     /// Get prices for one or more tokens from the Jupiter Price API v2
-    /// 
+    ///
     /// By default, prices are in terms of USDC. Use the vs_token parameter to get prices in terms of another token.
     pub async fn get_prices(&self, price_request: &PriceRequest) -> Result<PriceResponse, ClientError> {
-        let url = format!("{}/price/v2", self.base_path);
-        let response = Client::new()
-            .get(url)
-            .query(&price_request)
-            .send()
-            .await?;
-        check_status_code_and_deserialize(response).await
+        let url = format!("{}{}", self.base_path, self.endpoints.price_v2);
+        self.transport.get(url, price_request).await
     }
-    
+
+    /// Get prices for one or more tokens from the Jupiter Price API v1.
+    ///
+    /// Unlike [`get_prices`](Self::get_prices), ids may be token symbols as well as mint
+    /// addresses, and the response carries `mintSymbol`/`vsTokenSymbol` instead of confidence
+    /// or depth data.
+    pub async fn get_prices_v1(&self, price_request: &PriceV1Request) -> Result<PriceV1Response, ClientError> {
+        let url = format!("{}{}", self.base_path, self.endpoints.price_v1);
+        self.transport.get(url, price_request).await
+    }
+
+    /// Subscribe to a push-style feed of price updates instead of polling [`get_prices`]
+    /// directly.
+    ///
+    /// Internally polls the v2 price endpoint ([`ApiEndpoints::price_v2`], `/price/v2` by
+    /// default) on the given `interval` and yields a fresh item each
+    /// time the response changes; identical consecutive responses (same price map) are
+    /// suppressed. Transient request errors are surfaced as `Err` items without ending the
+    /// stream, so callers can keep iterating through blips. The stream stops polling as soon
+    /// as it is dropped.
+    ///
+    /// [`get_prices`]: JupiterSwapApiClient::get_prices
+    pub fn subscribe_prices(
+        &self,
+        request: PriceRequest,
+        interval: Duration,
+    ) -> Pin<Box<dyn Stream<Item = Result<PriceResponse, ClientError>> + Send + '_>> {
+        let ticker = tokio::time::interval(interval);
+        let last: Option<PriceResponse> = None;
+        Box::pin(futures::stream::unfold((ticker, last), move |(mut ticker, mut last)| {
+            let request = request.clone();
+            async move {
+                loop {
+                    ticker.tick().await;
+                    match self.get_prices(&request).await {
+                        Ok(response) => {
+                            if last.as_ref().map(|l| l.data == response.data).unwrap_or(false) {
+                                continue;
+                            }
+                            last = Some(response.clone());
+                            return Some((Ok(response), (ticker, last)));
+                        }
+                        Err(err) => return Some((Err(err), (ticker, last))),
+                    }
+                }
+            }
+        }))
+    }
+
+    /// Get prices for one or more tokens, filtering out any whose `confidence_level` or
+    /// staleness doesn't meet the given [`PriceQuality`].
+    ///
+    /// Always requests `show_extra_info`, since the confidence and timestamp data it needs
+    /// only comes back when extra info is present. A token is rejected (and recorded in
+    /// [`ValidatedPricesResponse::rejected`]) rather than erroring the whole call, so callers
+    /// can still act on the mints that did pass the bar.
+    pub async fn get_validated_prices(
+        &self,
+        request: &PriceRequest,
+        quality: &PriceQuality,
+    ) -> Result<ValidatedPricesResponse, ClientError> {
+        let request = PriceRequest {
+            show_extra_info: Some(true),
+            ..request.clone()
+        };
+        let response = self.get_prices(&request).await?;
+
+        let mut accepted = HashMap::new();
+        let mut rejected = HashMap::new();
+        for (mint, token_price) in response.data {
+            match price_quality::evaluate(token_price.extra_info.as_ref(), quality) {
+                Ok(()) => {
+                    accepted.insert(mint, token_price);
+                }
+                Err(reason) => {
+                    rejected.insert(mint, reason);
+                }
+            }
+        }
+
+        Ok(ValidatedPricesResponse {
+            accepted,
+            rejected,
+            time_taken: response.time_taken,
+        })
+    }
+
     /// Helper method to get the price for a single token in terms of USDC
     pub async fn get_token_price(&self, token_mint: &Pubkey) -> Result<PriceResponse, ClientError> {
         let request = PriceRequest::new_single(token_mint);
@@ -136,4 +325,63 @@ impl JupiterSwapApiClient {
         let request = PriceRequest::new_single(token_mint).with_extra_info(true);
         self.get_prices(&request).await
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use serde_json::json;
+
+    use crate::transport::MockTransport;
+
+    use super::*;
+
+    const MINT: &str = "So11111111111111111111111111111111111111112";
+
+    fn price_response_json(price: &str) -> serde_json::Value {
+        json!({
+            "data": {
+                MINT: {
+                    "id": MINT,
+                    "type": "derivedPrice",
+                    "price": price,
+                },
+            },
+            "timeTaken": 0.01,
+        })
+    }
+
+    #[tokio::test]
+    async fn subscribe_prices_dedups_identical_consecutive_responses() {
+        let transport = MockTransport::new().with_response(MINT, price_response_json("1.00"));
+        let client = JupiterSwapApiClient::with_transport("https://example.com".to_string(), transport);
+        let request = PriceRequest::new_single(&MINT.parse().unwrap());
+
+        let mut stream = client.subscribe_prices(request, Duration::from_millis(5));
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.data[MINT].price.to_string(), "1.00");
+
+        // The mocked response never changes, so no further item should be emitted.
+        let second = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+        assert!(second.is_err(), "expected no item for an unchanged response");
+    }
+
+    #[tokio::test]
+    async fn subscribe_prices_surfaces_errors_without_ending_the_stream() {
+        let transport = MockTransport::new();
+        let client = JupiterSwapApiClient::with_transport("https://example.com".to_string(), transport);
+        let request = PriceRequest::new_single(&MINT.parse().unwrap());
+
+        let mut stream = client.subscribe_prices(request, Duration::from_millis(5));
+
+        let first = stream.next().await.unwrap();
+        assert!(matches!(
+            first,
+            Err(ClientError::RequestFailed { status, .. }) if status == reqwest::StatusCode::NOT_FOUND
+        ));
+
+        let second = stream.next().await.unwrap();
+        assert!(matches!(second, Err(ClientError::RequestFailed { .. })));
+    }
 }
\ No newline at end of file
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
+use crate::price_amount::PriceAmount;
+
 
 /// Helper module for serializing/deserializing Option<u64> as string
 pub mod field_as_string_option {
@@ -48,7 +50,7 @@ pub struct PriceRequest {
 }
 
 /// Price information for a single token
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenPrice {
     /// The mint address of the token
@@ -56,42 +58,54 @@ pub struct TokenPrice {
     /// The type of price (usually "derivedPrice")
     pub r#type: String,
     /// The price of the token (vs USDC or the specified vsToken)
-    pub price: String,
+    #[serde(with = "crate::price_amount::field_as_string")]
+    pub price: PriceAmount,
     /// Extra information about the price (only present if showExtraInfo=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extra_info: Option<PriceExtraInfo>,
 }
 
 /// Last swapped price information
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LastSwappedPrice {
     /// Epoch seconds of the last Jupiter sell price
     pub last_jupiter_sell_at: Option<u64>,
     /// Price of last Jupiter sell
-    pub last_jupiter_sell_price: Option<String>,
+    #[serde(with = "crate::price_amount::field_as_string_option")]
+    pub last_jupiter_sell_price: Option<PriceAmount>,
     /// Epoch seconds of the last Jupiter buy price
     pub last_jupiter_buy_at: Option<u64>,
     /// Price of last Jupiter buy
-    pub last_jupiter_buy_price: Option<String>,
+    #[serde(with = "crate::price_amount::field_as_string_option")]
+    pub last_jupiter_buy_price: Option<PriceAmount>,
 }
 
 /// Quoted price information
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct QuotedPrice {
     /// The quoted buy price
-    pub buy_price: Option<String>,
+    #[serde(with = "crate::price_amount::field_as_string_option")]
+    pub buy_price: Option<PriceAmount>,
     /// Epoch seconds of when the buy quote was retrieved
     pub buy_at: Option<u64>,
     /// The quoted sell price
-    pub sell_price: Option<String>,
+    #[serde(with = "crate::price_amount::field_as_string_option")]
+    pub sell_price: Option<PriceAmount>,
     /// Epoch seconds of when the sell quote was retrieved
     pub sell_at: Option<u64>,
 }
 
+/// Which side of a trade a price impact estimate is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
 /// Price impact ratios for different depths
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceImpactRatio {
     /// Map of depth levels (10, 100, 1000 SOL) to impact percentages
@@ -101,7 +115,7 @@ pub struct PriceImpactRatio {
 }
 
 /// Depth information for price impacts
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DepthInfo {
     /// Price impact ratio for buy operations
@@ -111,7 +125,7 @@ pub struct DepthInfo {
 }
 
 /// Extra information about a token's price
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceExtraInfo {
     /// Information about the last swapped price
@@ -125,7 +139,7 @@ pub struct PriceExtraInfo {
 }
 
 /// Response from the price API
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceResponse {
     /// Map of token mint address to token price information
@@ -134,6 +148,43 @@ pub struct PriceResponse {
     pub time_taken: f64,
 }
 
+impl PriceExtraInfo {
+    /// Estimate the price impact (in percent) of trading `size_in_sol` SOL, by
+    /// piecewise-linearly interpolating between the depth buckets Jupiter reports (e.g. 10,
+    /// 100, 1000 SOL) for the requested `side`.
+    ///
+    /// Sizes outside the reported range are clamped to the smallest/largest bucket rather
+    /// than extrapolated. Returns `None` if depth data for `side` isn't present in the
+    /// response.
+    pub fn estimate_price_impact(&self, side: Side, size_in_sol: f64) -> Option<f64> {
+        let ratio = match side {
+            Side::Buy => self.depth.as_ref()?.buy_price_impact_ratio.as_ref()?,
+            Side::Sell => self.depth.as_ref()?.sell_price_impact_ratio.as_ref()?,
+        };
+
+        let mut buckets: Vec<(f64, f64)> = ratio
+            .depth
+            .iter()
+            .filter_map(|(size, impact)| size.parse::<f64>().ok().map(|size| (size, *impact)))
+            .collect();
+        buckets.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let (smallest, largest) = (*buckets.first()?, *buckets.last()?);
+        if size_in_sol <= smallest.0 {
+            return Some(smallest.1);
+        }
+        if size_in_sol >= largest.0 {
+            return Some(largest.1);
+        }
+
+        let upper_idx = buckets.partition_point(|(size, _)| *size < size_in_sol);
+        let (lower_size, lower_impact) = buckets[upper_idx - 1];
+        let (upper_size, upper_impact) = buckets[upper_idx];
+        let t = (size_in_sol - lower_size) / (upper_size - lower_size);
+        Some(lower_impact + t * (upper_impact - lower_impact))
+    }
+}
+
 impl PriceRequest {
     /// Create a new price request for a single token
     pub fn new_single(token_mint: &Pubkey) -> Self {
@@ -170,4 +221,72 @@ impl PriceRequest {
         self.show_extra_info = Some(show_extra_info);
         self
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extra_info_with_buy_depth(depth: &[(&str, f64)]) -> PriceExtraInfo {
+        PriceExtraInfo {
+            last_swapped_price: None,
+            quoted_price: None,
+            confidence_level: None,
+            depth: Some(DepthInfo {
+                buy_price_impact_ratio: Some(PriceImpactRatio {
+                    depth: depth.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+                    timestamp: 0,
+                }),
+                sell_price_impact_ratio: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn interpolates_between_the_two_bracketing_buckets() {
+        let info = extra_info_with_buy_depth(&[("10", 1.0), ("100", 2.0), ("1000", 5.0)]);
+        let impact = info.estimate_price_impact(Side::Buy, 55.0).unwrap();
+        assert_eq!(impact, 1.5);
+    }
+
+    #[test]
+    fn returns_the_bucket_value_on_an_exact_match() {
+        let info = extra_info_with_buy_depth(&[("10", 1.0), ("100", 2.0)]);
+        assert_eq!(info.estimate_price_impact(Side::Buy, 100.0), Some(2.0));
+    }
+
+    #[test]
+    fn clamps_to_the_smallest_bucket_below_the_range() {
+        let info = extra_info_with_buy_depth(&[("10", 1.0), ("100", 2.0)]);
+        assert_eq!(info.estimate_price_impact(Side::Buy, 1.0), Some(1.0));
+    }
+
+    #[test]
+    fn clamps_to_the_largest_bucket_above_the_range() {
+        let info = extra_info_with_buy_depth(&[("10", 1.0), ("100", 2.0)]);
+        assert_eq!(info.estimate_price_impact(Side::Buy, 10_000.0), Some(2.0));
+    }
+
+    #[test]
+    fn ignores_unparseable_depth_keys() {
+        let info = extra_info_with_buy_depth(&[("10", 1.0), ("not-a-number", 99.0), ("100", 2.0)]);
+        assert_eq!(info.estimate_price_impact(Side::Buy, 55.0), Some(1.5));
+    }
+
+    #[test]
+    fn returns_none_when_the_requested_side_has_no_depth_data() {
+        let info = extra_info_with_buy_depth(&[("10", 1.0)]);
+        assert_eq!(info.estimate_price_impact(Side::Sell, 55.0), None);
+    }
+
+    #[test]
+    fn returns_none_when_there_is_no_depth_info_at_all() {
+        let info = PriceExtraInfo {
+            last_swapped_price: None,
+            quoted_price: None,
+            confidence_level: None,
+            depth: None,
+        };
+        assert_eq!(info.estimate_price_impact(Side::Buy, 55.0), None);
+    }
 }
\ No newline at end of file
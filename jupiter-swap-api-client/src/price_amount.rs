@@ -0,0 +1,107 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// A decimal-typed price or amount carried over the wire as a JSON string.
+///
+/// Wraps a [`rust_decimal::Decimal`] instead of exposing the raw string, so parsing happens
+/// once and can't panic on a malformed response. Use [`field_as_string`] /
+/// [`field_as_string_option`] to (de)serialize it, mirroring
+/// [`crate::price::field_as_string_option`]; the wire format stays an unchanged JSON string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PriceAmount(Decimal);
+
+impl PriceAmount {
+    /// The underlying decimal value.
+    pub fn as_decimal(&self) -> Decimal {
+        self.0
+    }
+
+    /// Lossy conversion to `f64`, convenient for display or approximate math.
+    pub fn as_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Checked addition, returning `None` on overflow instead of panicking.
+    pub fn checked_add(&self, other: PriceAmount) -> Option<PriceAmount> {
+        self.0.checked_add(other.0).map(PriceAmount)
+    }
+
+    /// Checked subtraction, returning `None` on overflow instead of panicking.
+    pub fn checked_sub(&self, other: PriceAmount) -> Option<PriceAmount> {
+        self.0.checked_sub(other.0).map(PriceAmount)
+    }
+}
+
+impl fmt::Display for PriceAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for PriceAmount {
+    type Err = rust_decimal::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s).map(PriceAmount)
+    }
+}
+
+/// Serde helper for required `PriceAmount` string fields (e.g. `TokenPrice::price`).
+pub mod field_as_string {
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::PriceAmount;
+
+    pub fn serialize<S>(value: &PriceAmount, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<PriceAmount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        PriceAmount::from_str(&s).map_err(|_| serde::de::Error::custom("Failed to parse decimal price"))
+    }
+}
+
+/// Serde helper for optional `PriceAmount` string fields, mirroring
+/// [`crate::price::field_as_string_option`].
+pub mod field_as_string_option {
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::PriceAmount;
+
+    pub fn serialize<S>(value: &Option<PriceAmount>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.serialize_str(&v.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<PriceAmount>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: Option<String> = Option::deserialize(deserializer)?;
+        match s {
+            Some(s) => PriceAmount::from_str(&s)
+                .map(Some)
+                .map_err(|_| serde::de::Error::custom("Failed to parse decimal price")),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::price::{PriceExtraInfo, TokenPrice};
+
+/// Minimum acceptable confidence tier for a price, mirroring the `confidence_level` string
+/// (`"high"`, `"medium"`, `"low"`) reported by the Jupiter Price API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfidenceLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl ConfidenceLevel {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "high" => Some(Self::High),
+            "medium" => Some(Self::Medium),
+            "low" => Some(Self::Low),
+            _ => None,
+        }
+    }
+}
+
+/// Guardrails applied to a price before a caller should trust it.
+#[derive(Debug, Clone, Copy)]
+pub struct PriceQuality {
+    /// Reject any token whose `confidence_level` is below this tier.
+    pub min_confidence: ConfidenceLevel,
+    /// Reject any token whose most recent `last_jupiter_buy_at`/`last_jupiter_sell_at` is
+    /// older than this, in seconds.
+    pub max_age_secs: u64,
+}
+
+impl Default for PriceQuality {
+    /// Requires at least medium confidence and a price swapped within the last 60 seconds.
+    fn default() -> Self {
+        Self {
+            min_confidence: ConfidenceLevel::Medium,
+            max_age_secs: 60,
+        }
+    }
+}
+
+/// Why a token's price was rejected by [`PriceQuality`] guardrails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// `confidence_level` was missing, unparseable, or below `min_confidence`.
+    LowConfidence,
+    /// The most recent `last_jupiter_buy_at`/`last_jupiter_sell_at` is older than `max_age_secs`,
+    /// or missing entirely.
+    Stale,
+    /// `show_extra_info` data is required to validate a price but was not present in the
+    /// response.
+    MissingExtraInfo,
+}
+
+/// Prices split into those that passed [`PriceQuality`] guardrails and those rejected, along
+/// with why each rejected mint was dropped.
+#[derive(Debug, Clone)]
+pub struct ValidatedPricesResponse {
+    /// Token prices that met the confidence and staleness guardrails.
+    pub accepted: HashMap<String, TokenPrice>,
+    /// Mint id to the reason its price was rejected.
+    pub rejected: HashMap<String, RejectionReason>,
+    /// Time taken for the underlying request to complete.
+    pub time_taken: f64,
+}
+
+pub(crate) fn evaluate(extra_info: Option<&PriceExtraInfo>, quality: &PriceQuality) -> Result<(), RejectionReason> {
+    let extra_info = extra_info.ok_or(RejectionReason::MissingExtraInfo)?;
+
+    let confidence = extra_info
+        .confidence_level
+        .as_deref()
+        .and_then(ConfidenceLevel::parse)
+        .ok_or(RejectionReason::LowConfidence)?;
+    if confidence < quality.min_confidence {
+        return Err(RejectionReason::LowConfidence);
+    }
+
+    let last_swapped_at = extra_info
+        .last_swapped_price
+        .as_ref()
+        .and_then(|p| p.last_jupiter_buy_at.max(p.last_jupiter_sell_at))
+        .ok_or(RejectionReason::Stale)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let age = now.saturating_sub(last_swapped_at);
+    if age > quality.max_age_secs {
+        return Err(RejectionReason::Stale);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::price::LastSwappedPrice;
+
+    use super::*;
+
+    fn now_secs() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    fn extra_info(confidence_level: Option<&str>, last_swapped_at: Option<u64>) -> PriceExtraInfo {
+        PriceExtraInfo {
+            last_swapped_price: last_swapped_at.map(|at| LastSwappedPrice {
+                last_jupiter_sell_at: None,
+                last_jupiter_sell_price: None,
+                last_jupiter_buy_at: Some(at),
+                last_jupiter_buy_price: None,
+            }),
+            quoted_price: None,
+            confidence_level: confidence_level.map(str::to_string),
+            depth: None,
+        }
+    }
+
+    #[test]
+    fn accepts_a_fresh_price_meeting_the_confidence_bar() {
+        let info = extra_info(Some("high"), Some(now_secs()));
+        let quality = PriceQuality {
+            min_confidence: ConfidenceLevel::Medium,
+            max_age_secs: 60,
+        };
+        assert_eq!(evaluate(Some(&info), &quality), Ok(()));
+    }
+
+    #[test]
+    fn rejects_a_confidence_below_the_threshold() {
+        let info = extra_info(Some("low"), Some(now_secs()));
+        let quality = PriceQuality {
+            min_confidence: ConfidenceLevel::Medium,
+            max_age_secs: 60,
+        };
+        assert_eq!(evaluate(Some(&info), &quality), Err(RejectionReason::LowConfidence));
+    }
+
+    #[test]
+    fn rejects_an_unparseable_confidence_level() {
+        let info = extra_info(Some("unknown"), Some(now_secs()));
+        let quality = PriceQuality::default();
+        assert_eq!(evaluate(Some(&info), &quality), Err(RejectionReason::LowConfidence));
+    }
+
+    #[test]
+    fn rejects_a_price_older_than_max_age() {
+        let info = extra_info(Some("high"), Some(now_secs().saturating_sub(120)));
+        let quality = PriceQuality {
+            min_confidence: ConfidenceLevel::Medium,
+            max_age_secs: 60,
+        };
+        assert_eq!(evaluate(Some(&info), &quality), Err(RejectionReason::Stale));
+    }
+
+    #[test]
+    fn rejects_a_price_with_no_last_swapped_timestamp() {
+        let info = extra_info(Some("high"), None);
+        let quality = PriceQuality::default();
+        assert_eq!(evaluate(Some(&info), &quality), Err(RejectionReason::Stale));
+    }
+
+    #[test]
+    fn rejects_when_extra_info_is_missing() {
+        let quality = PriceQuality::default();
+        assert_eq!(evaluate(None, &quality), Err(RejectionReason::MissingExtraInfo));
+    }
+}
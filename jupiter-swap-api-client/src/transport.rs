@@ -0,0 +1,242 @@
+use std::future::Future;
+
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{check_status_code_and_deserialize, ClientConfig, ClientError};
+
+/// Abstracts the HTTP calls made by [`JupiterSwapApiClient`](crate::JupiterSwapApiClient),
+/// so a [`MockTransport`] can stand in for a live Jupiter instance in tests instead of hitting
+/// the network.
+///
+/// [`ReqwestTransport`] is the default, real-network implementation; `JupiterSwapApiClient` is
+/// generic over this trait and defaults to it.
+pub trait JupiterTransport: Clone + Send + Sync {
+    /// Issue a GET request to `url` with `query` serialized onto the query string, and
+    /// deserialize the JSON response as `T`.
+    fn get<T: DeserializeOwned>(
+        &self,
+        url: String,
+        query: &(impl Serialize + Sync),
+    ) -> impl Future<Output = Result<T, ClientError>> + Send;
+
+    /// Issue a POST request to `url` with `body` serialized as the JSON payload, and
+    /// deserialize the JSON response as `T`.
+    fn post<T: DeserializeOwned>(
+        &self,
+        url: String,
+        body: &(impl Serialize + Sync),
+    ) -> impl Future<Output = Result<T, ClientError>> + Send;
+
+    /// Issue a POST request to `url` with `body` as the JSON payload and `query` serialized
+    /// onto the query string, and deserialize the JSON response as `T`.
+    fn post_with_query<T: DeserializeOwned>(
+        &self,
+        url: String,
+        query: &(impl Serialize + Sync),
+        body: &(impl Serialize + Sync),
+    ) -> impl Future<Output = Result<T, ClientError>> + Send;
+}
+
+/// The default [`JupiterTransport`], backed by a pooled [`reqwest::Client`].
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Build a transport from a [`ClientConfig`], used by
+    /// [`JupiterSwapApiClient::new_with_config`](crate::JupiterSwapApiClient::new_with_config).
+    pub(crate) fn new(config: ClientConfig) -> Self {
+        let client = Client::builder()
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .timeout(config.timeout)
+            .default_headers(config.default_headers)
+            .build()
+            .expect("failed to build reqwest client");
+        Self { client }
+    }
+}
+
+impl JupiterTransport for ReqwestTransport {
+    async fn get<T: DeserializeOwned>(
+        &self,
+        url: String,
+        query: &(impl Serialize + Sync),
+    ) -> Result<T, ClientError> {
+        let response = self.client.get(url).query(query).send().await?;
+        check_status_code_and_deserialize(response).await
+    }
+
+    async fn post<T: DeserializeOwned>(
+        &self,
+        url: String,
+        body: &(impl Serialize + Sync),
+    ) -> Result<T, ClientError> {
+        let response = self.client.post(url).json(body).send().await?;
+        check_status_code_and_deserialize(response).await
+    }
+
+    async fn post_with_query<T: DeserializeOwned>(
+        &self,
+        url: String,
+        query: &(impl Serialize + Sync),
+        body: &(impl Serialize + Sync),
+    ) -> Result<T, ClientError> {
+        let response = self.client.post(url).query(query).json(body).send().await?;
+        check_status_code_and_deserialize(response).await
+    }
+}
+
+/// Well-known request fields [`MockTransport`] checks, in priority order, to identify which
+/// mint a `get`/`post` call is about.
+const MINT_FIELDS: &[&str] = &["ids", "inputMint", "userPublicKey", "outputMint"];
+
+/// A [`JupiterTransport`] that returns canned responses instead of making real HTTP calls, so
+/// strategies built on [`JupiterSwapApiClient`](crate::JupiterSwapApiClient) can be unit-tested
+/// offline.
+///
+/// Register a response with [`MockTransport::with_response`], keyed by the mint it should be
+/// returned for. A call is matched by looking for that mint in whichever of `ids`,
+/// `inputMint`, `userPublicKey`, or `outputMint` is present in the request's query or body;
+/// `ids` lists (as used by the price API) are matched if the mint appears anywhere in the
+/// comma-separated list. A call with no matching mint returns a
+/// [`ClientError::RequestFailed`] with a 404 status, mirroring a real Jupiter 404.
+///
+/// Responses are registered as raw [`serde_json::Value`] (e.g. via the `json!` macro) rather
+/// than as the response structs themselves, since those are wire-response types that only
+/// derive `Deserialize`.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    responses: std::collections::HashMap<String, Value>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the JSON response to return for requests about `mint`.
+    pub fn with_response(mut self, mint: impl Into<String>, response: impl Into<Value>) -> Self {
+        self.responses.insert(mint.into(), response.into());
+        self
+    }
+
+    fn resolve<T: DeserializeOwned>(&self, mint: Option<String>) -> Result<T, ClientError> {
+        let value = mint
+            .as_deref()
+            .and_then(|mint| self.lookup(mint))
+            .ok_or_else(|| ClientError::RequestFailed {
+                status: reqwest::StatusCode::NOT_FOUND,
+                body: format!("no mock response registered for mint {mint:?}"),
+            })?;
+        serde_json::from_value(value.clone()).map_err(|err| ClientError::RequestFailed {
+            status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            body: format!("mock response did not match the expected type: {err}"),
+        })
+    }
+
+    fn lookup(&self, mint: &str) -> Option<&Value> {
+        if let Some(value) = self.responses.get(mint) {
+            return Some(value);
+        }
+        mint.split(',').find_map(|id| self.responses.get(id.trim()))
+    }
+}
+
+fn find_mint(value: &impl Serialize) -> Option<String> {
+    let value = serde_json::to_value(value).ok()?;
+    let object = value.as_object()?;
+    MINT_FIELDS.iter().find_map(|field| {
+        object
+            .get(*field)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    })
+}
+
+impl JupiterTransport for MockTransport {
+    async fn get<T: DeserializeOwned>(
+        &self,
+        _url: String,
+        query: &(impl Serialize + Sync),
+    ) -> Result<T, ClientError> {
+        self.resolve(find_mint(query))
+    }
+
+    async fn post<T: DeserializeOwned>(
+        &self,
+        _url: String,
+        body: &(impl Serialize + Sync),
+    ) -> Result<T, ClientError> {
+        self.resolve(find_mint(body))
+    }
+
+    async fn post_with_query<T: DeserializeOwned>(
+        &self,
+        _url: String,
+        query: &(impl Serialize + Sync),
+        body: &(impl Serialize + Sync),
+    ) -> Result<T, ClientError> {
+        self.resolve(find_mint(query).or_else(|| find_mint(body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use crate::price::PriceRequest;
+    use crate::price_amount::PriceAmount;
+    use crate::{ClientError, JupiterSwapApiClient};
+
+    use super::MockTransport;
+
+    const MINT: &str = "So11111111111111111111111111111111111111112";
+
+    fn price_response_json() -> serde_json::Value {
+        json!({
+            "data": {
+                MINT: {
+                    "id": MINT,
+                    "type": "derivedPrice",
+                    "price": "1.23",
+                },
+            },
+            "timeTaken": 0.01,
+        })
+    }
+
+    #[tokio::test]
+    async fn returns_the_registered_response_for_a_matching_mint() {
+        let transport = MockTransport::new().with_response(MINT, price_response_json());
+        let client = JupiterSwapApiClient::with_transport("https://example.com".to_string(), transport);
+
+        let response = client
+            .get_prices(&PriceRequest::new_single(&MINT.parse().unwrap()))
+            .await
+            .unwrap();
+
+        assert_eq!(response.data[MINT].price, PriceAmount::from_str("1.23").unwrap());
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_an_unregistered_mint() {
+        let transport = MockTransport::new();
+        let client = JupiterSwapApiClient::with_transport("https://example.com".to_string(), transport);
+
+        let err = client
+            .get_prices(&PriceRequest::new_single(&MINT.parse().unwrap()))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ClientError::RequestFailed { status, .. } if status == reqwest::StatusCode::NOT_FOUND
+        ));
+    }
+}